@@ -0,0 +1,84 @@
+use std::fmt;
+
+/// Errors produced while talking to the Last.fm API.
+///
+/// Last.fm responds with HTTP 200 even for application-level failures (bad API key,
+/// unknown user, rate limiting, ...), wrapping them in a `{"error": <code>, "message": ..}`
+/// envelope instead of a non-2xx status. [`crate::lastfm_handler::LastFMHandler`] parses
+/// that envelope itself so callers get a typed [`LastFmError::Api`] instead of an opaque
+/// JSON deserialization failure.
+#[derive(Debug)]
+pub enum LastFmError {
+    /// The request itself failed (network error, timeout, TLS, ...).
+    Transport(reqwest::Error),
+    /// Last.fm accepted the request but reported an application-level error.
+    Api { code: u16, message: String },
+    /// The response body was neither a valid error envelope nor the expected shape.
+    Decode(serde_json::Error),
+    /// `LAST_FM_API_KEY` was not set in the environment.
+    MissingApiKey,
+    /// A multi-page fetch returned fewer tracks than Last.fm reported existed, with one
+    /// or more pages coming back short of a full page despite not being the last one —
+    /// a sign of a dropped or truncated response rather than genuine end-of-history.
+    IncompleteResult {
+        expected: u32,
+        got: u32,
+        missing_pages: Vec<u32>,
+    },
+    /// Last.fm responded with HTTP 429, optionally telling us how long to wait via
+    /// `Retry-After`. `LastFMHandler::get` waits `retry_after` between retries without
+    /// holding a concurrency permit, and returns this error once its retries run out.
+    RateLimited { retry_after: std::time::Duration },
+}
+
+impl fmt::Display for LastFmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LastFmError::Transport(e) => write!(f, "request to Last.fm failed: {e}"),
+            LastFmError::Api { code, message } => {
+                write!(f, "Last.fm API error {code}: {message}")
+            }
+            LastFmError::Decode(e) => write!(f, "failed to parse Last.fm response: {e}"),
+            LastFmError::MissingApiKey => {
+                write!(f, "LAST_FM_API_KEY is not set in the environment")
+            }
+            LastFmError::IncompleteResult {
+                expected,
+                got,
+                missing_pages,
+            } => write!(
+                f,
+                "expected {expected} tracks but got {got}; pages came back short: {missing_pages:?}"
+            ),
+            LastFmError::RateLimited { retry_after } => {
+                write!(f, "rate limited by Last.fm, retry after {retry_after:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LastFmError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LastFmError::Transport(e) => Some(e),
+            LastFmError::Decode(e) => Some(e),
+            LastFmError::Api { .. }
+            | LastFmError::MissingApiKey
+            | LastFmError::IncompleteResult { .. }
+            | LastFmError::RateLimited { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for LastFmError {
+    fn from(e: reqwest::Error) -> Self {
+        LastFmError::Transport(e)
+    }
+}
+
+/// Last.fm's error envelope, returned with HTTP 200 in place of the requested payload.
+#[derive(Debug, serde::Deserialize)]
+pub struct ApiErrorEnvelope {
+    pub error: u16,
+    pub message: String,
+}
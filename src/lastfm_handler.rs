@@ -1,37 +1,88 @@
+use crate::error::{ApiErrorEnvelope, LastFmError};
 use crate::types::*;
 use crate::url_builder::{QueryParams, Url};
 
-use reqwest::Error;
+use metrics::{counter, histogram};
+use rand::Rng;
 use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 use std::env;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::Semaphore;
+use tracing::{debug, instrument, trace, warn};
 
 const API_MAX_LIMIT: u32 = 1000;
 
+/// Time window for Last.fm's `user.gettop*` endpoints, mapped to their `period` param.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Period {
+    Overall,
+    SevenDay,
+    OneMonth,
+    ThreeMonth,
+    SixMonth,
+    TwelveMonth,
+}
+
+impl Period {
+    fn as_query_value(self) -> &'static str {
+        match self {
+            Period::Overall => "overall",
+            Period::SevenDay => "7day",
+            Period::OneMonth => "1month",
+            Period::ThreeMonth => "3month",
+            Period::SixMonth => "6month",
+            Period::TwelveMonth => "12month",
+        }
+    }
+}
+
 const CHUNK_MULTIPLIER: u32 = 5;
 const CHUNK_SIZE: u32 = API_MAX_LIMIT * CHUNK_MULTIPLIER;
 
+// Default ceiling on requests in flight at once; overridable via `with_max_concurrency`.
+const DEFAULT_MAX_CONCURRENCY: usize = 10;
+
+const MAX_RETRIES: u32 = 3;
+const BASE_BACKOFF: Duration = Duration::from_millis(200);
+const MAX_BACKOFF: Duration = Duration::from_secs(5);
+
 #[derive(Debug)]
 pub struct LastFMHandler {
     url: Url,
     base_options: QueryParams,
+    semaphore: Arc<Semaphore>,
 }
 
 impl LastFMHandler {
-    pub fn new(url: Url, username: &str) -> Self {
+    pub fn new(url: Url, username: &str) -> Result<Self, LastFmError> {
+        let api_key = env::var("LAST_FM_API_KEY").map_err(|_| LastFmError::MissingApiKey)?;
+
         let mut base_options = QueryParams::new();
-        base_options.insert("api_key".to_string(), env::var("LAST_FM_API_KEY").unwrap());
+        base_options.insert("api_key".to_string(), api_key);
         base_options.insert("limit".to_string(), API_MAX_LIMIT.to_string());
         base_options.insert("format".to_string(), "json".to_string());
         base_options.insert("user".to_string(), username.to_string());
 
-        LastFMHandler { url, base_options }
+        Ok(LastFMHandler {
+            url,
+            base_options,
+            semaphore: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENCY)),
+        })
+    }
+
+    /// Overrides how many requests this handler will keep in flight at once. Useful for
+    /// staying under Last.fm's rate limits on large multi-page pulls.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.semaphore = Arc::new(Semaphore::new(max_concurrency));
+        self
     }
 
     pub async fn get_user_loved_tracks(
         &self,
         limit: Option<u32>,
-    ) -> Result<Vec<LovedTrack>, Error> {
+    ) -> Result<Vec<LovedTrack>, LastFmError> {
         self.get_user_tracks::<UserLovedTracks>("user.getlovedtracks", limit)
             .await
     }
@@ -39,16 +90,313 @@ impl LastFMHandler {
     pub async fn get_user_recent_tracks(
         &self,
         limit: Option<u32>,
-    ) -> Result<Vec<RecentTrack>, Error> {
+    ) -> Result<Vec<RecentTrack>, LastFmError> {
         self.get_user_tracks::<UserRecentTracks>("user.getrecenttracks", limit)
             .await
     }
 
+    pub async fn get_user_top_artists(
+        &self,
+        period: Period,
+        limit: Option<u32>,
+    ) -> Result<Vec<TopArtist>, LastFmError> {
+        self.get_user_top_items::<UserTopArtists>("user.gettopartists", period, limit)
+            .await
+    }
+
+    pub async fn get_user_top_albums(
+        &self,
+        period: Period,
+        limit: Option<u32>,
+    ) -> Result<Vec<TopAlbum>, LastFmError> {
+        self.get_user_top_items::<UserTopAlbums>("user.gettopalbums", period, limit)
+            .await
+    }
+
+    pub async fn get_user_top_tracks(
+        &self,
+        period: Period,
+        limit: Option<u32>,
+    ) -> Result<Vec<TopTrack>, LastFmError> {
+        self.get_user_top_items::<UserTopTracks>("user.gettoptracks", period, limit)
+            .await
+    }
+
+    /// Fetches a user's top items for `period`, walking as many pages as needed when
+    /// `limit` exceeds `API_MAX_LIMIT`. This is the same chunked-and-parallel strategy
+    /// as [`LastFMHandler::get_user_tracks`], just keyed on [`ItemContainer`] instead of
+    /// `TrackContainer` since every request here also carries a `period` param.
+    async fn get_user_top_items<T: DeserializeOwned + ItemContainer>(
+        &self,
+        method: &str,
+        period: Period,
+        limit: Option<u32>,
+    ) -> Result<Vec<T::Item>, LastFmError> {
+        let mut all_items: Vec<T::Item> = Vec::new();
+
+        let final_limit = limit.unwrap_or(API_MAX_LIMIT);
+
+        // Make an initial request to get the total number of items
+        let mut base_params: QueryParams = HashMap::new();
+        base_params.insert("period".to_string(), period.as_query_value().to_string());
+        base_params.insert("limit".to_string(), "1".to_string()); // Request only 1 item to get the total count
+
+        let initial_response: T = self.get(method, &base_params).await?;
+        let total_items = initial_response.total_items();
+
+        // Determine the actual limit to use
+        let actual_limit = final_limit.min(total_items);
+
+        // Pages that came back short of `API_MAX_LIMIT` items despite not being the
+        // container's actual last page — a sign of a dropped/truncated response rather
+        // than a legitimate end-of-history.
+        let mut missing_pages: Vec<u32> = Vec::new();
+
+        if actual_limit > API_MAX_LIMIT {
+            let needed_chunks = ((actual_limit / CHUNK_SIZE) as f32).floor() as u32;
+
+            debug!(needed_chunks, "fetching top items in chunks");
+
+            for i in 0..needed_chunks {
+                let mut all_fetches = Vec::new();
+                let mut pages = Vec::new();
+
+                trace!(chunk = i, "starting chunk");
+
+                for j in 0..CHUNK_MULTIPLIER {
+                    trace!(chunk = i, offset = j, "queuing chunked fetch");
+
+                    let chunk_offset = i * CHUNK_MULTIPLIER + (j + 1);
+                    let final_limit_str = API_MAX_LIMIT.to_string();
+                    let final_offset_str = chunk_offset.to_string();
+
+                    let mut params = self.base_options.clone();
+                    params.insert("period".to_string(), period.as_query_value().to_string());
+                    params.insert("limit".to_string(), final_limit_str);
+                    params.insert("page".to_string(), final_offset_str);
+
+                    let fetch = async move { self.get::<T>(method, &params).await };
+                    all_fetches.push(fetch);
+                    pages.push(chunk_offset);
+                }
+
+                let chunk_results = futures::future::join_all(all_fetches).await;
+
+                for (page, result) in pages.into_iter().zip(chunk_results) {
+                    match result {
+                        Ok(container) => {
+                            Self::record_if_incomplete_items(
+                                &container,
+                                page,
+                                API_MAX_LIMIT,
+                                &mut missing_pages,
+                            );
+                            all_items.extend(container.items());
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+
+            // Handle remainder
+            let remainder = actual_limit % CHUNK_SIZE;
+            debug!(remainder, "fetching remainder pages");
+            let needed_calls = (remainder as f32 / API_MAX_LIMIT as f32).ceil() as u32;
+
+            let mut all_fetches = Vec::new();
+            let mut pages = Vec::new();
+
+            for i in 0..needed_calls {
+                let page = CHUNK_MULTIPLIER * needed_chunks + i + 1;
+                let final_limit_str = API_MAX_LIMIT.to_string();
+                let final_offset_str = page.to_string();
+
+                let mut params = self.base_options.clone();
+                params.insert("period".to_string(), period.as_query_value().to_string());
+                params.insert("limit".to_string(), final_limit_str);
+                params.insert("page".to_string(), final_offset_str);
+
+                let fetch = async move { self.get::<T>(method, &params).await };
+                all_fetches.push(fetch);
+                pages.push(page);
+            }
+
+            let chunk_results = futures::future::join_all(all_fetches).await;
+
+            for (page, result) in pages.into_iter().zip(chunk_results) {
+                match result {
+                    Ok(container) => {
+                        Self::record_if_incomplete_items(
+                            &container,
+                            page,
+                            API_MAX_LIMIT,
+                            &mut missing_pages,
+                        );
+                        all_items.extend(container.items());
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        } else {
+            let mut base_params: QueryParams = HashMap::new();
+            base_params.insert("period".to_string(), period.as_query_value().to_string());
+            base_params.insert("limit".to_string(), actual_limit.to_string());
+
+            let response: T = self.get(method, &base_params).await?;
+
+            Self::record_if_incomplete_items(&response, 1, actual_limit, &mut missing_pages);
+            all_items.extend(response.items());
+        }
+
+        // trunc the vector to the final limit
+        let final_items: Vec<T::Item> = all_items.into_iter().take(actual_limit as usize).collect();
+
+        if !missing_pages.is_empty() {
+            let got = final_items.len() as u32;
+            warn!(
+                expected = actual_limit,
+                got,
+                missing_pages = ?missing_pages,
+                "some pages came back short, result is likely incomplete"
+            );
+            return Err(LastFmError::IncompleteResult {
+                expected: actual_limit,
+                got,
+                missing_pages,
+            });
+        }
+
+        Ok(final_items)
+    }
+
+    /// Lazily streams a user's loved tracks one page at a time instead of collecting
+    /// the whole history into memory. See [`paginate`] for the buffering strategy.
+    pub fn get_user_loved_tracks_stream(
+        &self,
+    ) -> impl futures::Stream<Item = Result<LovedTrack, LastFmError>> + '_ {
+        paginate::<UserLovedTracks>(self, "user.getlovedtracks")
+    }
+
+    /// Lazily streams a user's recent tracks one page at a time instead of collecting
+    /// the whole history into memory. See [`paginate`] for the buffering strategy.
+    pub fn get_user_recent_tracks_stream(
+        &self,
+    ) -> impl futures::Stream<Item = Result<RecentTrack, LastFmError>> + '_ {
+        paginate::<UserRecentTracks>(self, "user.getrecenttracks")
+    }
+
+    /// Fetches recent tracks scrobbled between `from` and `to` (UTC seconds, Last.fm's
+    /// `from`/`to` params), returned in chronological (oldest-first) order.
+    ///
+    /// This is meant for incremental/resumable syncing: persist the `uts` of the last
+    /// track you processed and pass `from = last_uts + 1` on the next call to only pick
+    /// up genuinely new scrobbles. The currently-playing track (if any) has no `date`
+    /// and is skipped when determining the resume cursor.
+    ///
+    /// Pages are walked from the last page down to the first so tracks can be yielded
+    /// oldest-first without buffering and reversing the whole history. `total`/`totalPages`
+    /// are re-read from every response (not cached from the first page) since Last.fm's
+    /// counts can shift as new scrobbles land mid-walk.
+    ///
+    /// If `totalPages` *shrinks* mid-walk (a scrobble in range was deleted), the current
+    /// page index is simply clamped down. If it *grows* instead (a new scrobble landed),
+    /// every page boundary above it shifts, which would otherwise make the walk re-read a
+    /// now-stale page number and interleave tracks out of order — so a growth is treated
+    /// as a sign the range is non-quiescent and the whole walk is restarted from a fresh
+    /// probe, up to `MAX_RESTARTS` times. This only yields a correct oldest-first order
+    /// for a range that stops changing within that many restarts; a history under
+    /// continuous, uninterrupted scrobbling during the call isn't guaranteed to converge.
+    pub async fn get_user_recent_tracks_since(
+        &self,
+        from: i64,
+        to: Option<i64>,
+    ) -> Result<Vec<RecentTrack>, LastFmError> {
+        const MAX_RESTARTS: u32 = 3;
+
+        let method = "user.getrecenttracks";
+
+        let mut base_params: QueryParams = HashMap::new();
+        base_params.insert("from".to_string(), from.to_string());
+        if let Some(to) = to {
+            base_params.insert("to".to_string(), to.to_string());
+        }
+        base_params.insert("limit".to_string(), API_MAX_LIMIT.to_string());
+
+        let mut restarts = 0;
+
+        'restart: loop {
+            // Probe page 1 to learn how many pages currently cover this range.
+            let mut probe_params = base_params.clone();
+            probe_params.insert("page".to_string(), "1".to_string());
+            let probe: UserRecentTracks = self.get(method, &probe_params).await?;
+            let mut total_pages = probe.recenttracks.attr.total_pages.max(1);
+
+            let mut ordered_tracks: Vec<RecentTrack> = Vec::new();
+            let mut page = total_pages;
+
+            while page >= 1 {
+                let mut page_params = base_params.clone();
+                page_params.insert("page".to_string(), page.to_string());
+
+                let response: UserRecentTracks = self.get(method, &page_params).await?;
+
+                // Recompute bounds from this response: totalPages can shift between
+                // pages as scrobbles are added or removed, so a cached value from the
+                // initial count can't be trusted for the rest of the walk.
+                let observed_total_pages = response.recenttracks.attr.total_pages.max(1);
+                if observed_total_pages > total_pages {
+                    // Growth shifts every page boundary above the insertion point, so
+                    // pages already consumed no longer line up with this response's
+                    // numbering. Restart rather than risk duplicated/out-of-order uts.
+                    restarts += 1;
+                    if restarts > MAX_RESTARTS {
+                        warn!(
+                            method,
+                            restarts, "recent-tracks range kept growing mid-walk, giving up on a fully quiescent read"
+                        );
+                    } else {
+                        continue 'restart;
+                    }
+                }
+                total_pages = observed_total_pages;
+                if page > total_pages {
+                    page = total_pages;
+                    continue;
+                }
+
+                // Last.fm returns each page newest-first; reverse it so appending pages
+                // (walked from last to first) yields the whole history oldest-first.
+                let mut page_tracks = response.recenttracks.track;
+                page_tracks.reverse();
+                ordered_tracks.extend(page_tracks);
+
+                if page == 1 {
+                    break;
+                }
+                page -= 1;
+            }
+
+            return Ok(ordered_tracks);
+        }
+    }
+
+    /// Returns the `date.uts` of the most recent *finished* scrobble in `tracks`, i.e.
+    /// skipping the "now playing" entry (which has no `date`). Feed `uts + 1` back into
+    /// [`LastFMHandler::get_user_recent_tracks_since`] as `from` to resume where this
+    /// batch left off.
+    pub fn last_processed_uts(tracks: &[RecentTrack]) -> Option<i64> {
+        tracks
+            .iter()
+            .rev()
+            .find_map(|track| track.date.as_ref())
+            .and_then(|date| date.uts.parse().ok())
+    }
+
     async fn get_user_tracks<T: DeserializeOwned + TrackContainer>(
         &self,
         method: &str,
         limit: Option<u32>,
-    ) -> Result<Vec<T::TrackType>, Error> {
+    ) -> Result<Vec<T::TrackType>, LastFmError> {
         let mut all_tracks: Vec<T::TrackType> = Vec::new();
 
         let final_limit = limit.unwrap_or(API_MAX_LIMIT);
@@ -57,24 +405,30 @@ impl LastFMHandler {
         let mut base_params: QueryParams = HashMap::new();
         base_params.insert("limit".to_string(), "1".to_string()); // Request only 1 track to get the total count
 
-        let initial_response: T = self.fetch(method, &base_params).await?;
+        let initial_response: T = self.get(method, &base_params).await?;
         let total_tracks = initial_response.total_tracks();
 
         // Determine the actual limit to use
         let actual_limit = final_limit.min(total_tracks);
 
+        // Pages that came back short of `API_MAX_LIMIT` tracks despite not being the
+        // container's actual last page — a sign of a dropped/truncated response rather
+        // than a legitimate end-of-history page.
+        let mut missing_pages: Vec<u32> = Vec::new();
+
         if actual_limit > API_MAX_LIMIT {
             let needed_chunks = ((actual_limit / CHUNK_SIZE) as f32).floor() as u32;
 
-            println!("Needed chunks: {}", needed_chunks);
+            debug!(needed_chunks, "fetching tracks in chunks");
 
             for i in 0..needed_chunks {
                 let mut all_fetches = Vec::new();
+                let mut pages = Vec::new();
 
-                println!("looping through chunks {}", i);
+                trace!(chunk = i, "starting chunk");
 
                 for j in 0..CHUNK_MULTIPLIER {
-                    println!("looping through chunk multiplier {}", j);
+                    trace!(chunk = i, offset = j, "queuing chunked fetch");
 
                     let chunk_offset = i * CHUNK_MULTIPLIER + (j + 1);
                     let final_limit_str = API_MAX_LIMIT.to_string();
@@ -86,18 +440,27 @@ impl LastFMHandler {
                     params.insert("page".to_string(), final_offset_str);
 
                     // Use async block to extend the lifetime of params
-                    let fetch = async move { self.fetch::<T>(method, &params).await };
+                    let fetch = async move { self.get::<T>(method, &params).await };
                     all_fetches.push(fetch);
+                    pages.push(chunk_offset);
                 }
 
                 // Await all fetches and collect results
                 let chunk_results = futures::future::join_all(all_fetches).await;
 
                 // Process and extend all_tracks with the results
-                for result in chunk_results {
+                for (page, result) in pages.into_iter().zip(chunk_results) {
                     // Handle potential errors and add tracks
                     match result {
-                        Ok(tracks) => all_tracks.extend(tracks.tracks()),
+                        Ok(container) => {
+                            Self::record_if_incomplete(
+                                &container,
+                                page,
+                                API_MAX_LIMIT,
+                                &mut missing_pages,
+                            );
+                            all_tracks.extend(container.tracks());
+                        }
                         Err(e) => return Err(e), // Or handle errors as appropriate
                     }
                 }
@@ -105,28 +468,39 @@ impl LastFMHandler {
 
             // Handle remainder
             let remainder = actual_limit % CHUNK_SIZE;
-            println!("Remainder: {}", remainder);
+            debug!(remainder, "fetching remainder pages");
             let needed_calls = (remainder as f32 / API_MAX_LIMIT as f32).ceil() as u32;
 
             let mut all_fetches = Vec::new();
+            let mut pages = Vec::new();
 
             for i in 0..needed_calls {
+                let page = CHUNK_MULTIPLIER * needed_chunks + i + 1;
                 let final_limit_str = API_MAX_LIMIT.to_string();
-                let final_offset_str = (CHUNK_MULTIPLIER * needed_chunks + i + 1).to_string();
+                let final_offset_str = page.to_string();
 
                 let mut params = self.base_options.clone();
                 params.insert("limit".to_string(), final_limit_str);
                 params.insert("page".to_string(), final_offset_str);
 
-                let fetch = async move { self.fetch::<T>(method, &params).await };
+                let fetch = async move { self.get::<T>(method, &params).await };
                 all_fetches.push(fetch);
+                pages.push(page);
             }
 
             let chunk_results = futures::future::join_all(all_fetches).await;
 
-            for result in chunk_results {
+            for (page, result) in pages.into_iter().zip(chunk_results) {
                 match result {
-                    Ok(tracks) => all_tracks.extend(tracks.tracks()),
+                    Ok(container) => {
+                        Self::record_if_incomplete(
+                            &container,
+                            page,
+                            API_MAX_LIMIT,
+                            &mut missing_pages,
+                        );
+                        all_tracks.extend(container.tracks());
+                    }
                     Err(e) => return Err(e),
                 }
             }
@@ -136,64 +510,219 @@ impl LastFMHandler {
 
             base_params.insert("limit".to_string(), final_limit_str);
 
-            let response: T = self.fetch(method, &base_params).await?;
+            let response: T = self.get(method, &base_params).await?;
 
+            Self::record_if_incomplete(&response, 1, actual_limit, &mut missing_pages);
             all_tracks.extend(response.tracks());
         }
 
         // trunc the vector to the final limit
-        let final_tracks = all_tracks.into_iter().take(actual_limit as usize).collect();
+        let final_tracks: Vec<T::TrackType> =
+            all_tracks.into_iter().take(actual_limit as usize).collect();
+
+        if !missing_pages.is_empty() {
+            let got = final_tracks.len() as u32;
+            warn!(
+                expected = actual_limit,
+                got,
+                missing_pages = ?missing_pages,
+                "some pages came back short, result is likely incomplete"
+            );
+            return Err(LastFmError::IncompleteResult {
+                expected: actual_limit,
+                got,
+                missing_pages,
+            });
+        }
 
         Ok(final_tracks)
     }
 
-    async fn fetch<T: DeserializeOwned>(
+    /// Flags `page` in `missing_pages` if the container returned fewer tracks than
+    /// `expected` (the limit that page was actually requested with) while reporting
+    /// that more pages existed beyond it — i.e. the page was short for a reason other
+    /// than genuinely being the end of the history.
+    fn record_if_incomplete<T: TrackContainer>(
+        container: &T,
+        page: u32,
+        expected: u32,
+        missing_pages: &mut Vec<u32>,
+    ) {
+        if container.page_track_count() < expected && page < container.total_pages() {
+            missing_pages.push(page);
+        }
+    }
+
+    /// Analogous to [`LastFMHandler::record_if_incomplete`], but for [`ItemContainer`]
+    /// responses.
+    fn record_if_incomplete_items<T: ItemContainer>(
+        container: &T,
+        page: u32,
+        expected: u32,
+        missing_pages: &mut Vec<u32>,
+    ) {
+        if container.page_item_count() < expected && page < container.total_pages() {
+            missing_pages.push(page);
+        }
+    }
+
+    /// Acquires a concurrency permit and performs a fully-retried, metrics- and
+    /// tracing-instrumented request for the given Last.fm `method`, deserializing the
+    /// JSON payload into `T`. Retries a transient failure (see `is_retryable`) up to `MAX_RETRIES` times, waiting out a 429's
+    /// `retry_after` or otherwise using exponential backoff (base `BASE_BACKOFF`, capped at `MAX_BACKOFF`, plus jitter
+    /// to avoid synchronized retries across concurrently-chunked requests). This is the
+    /// one path all endpoint methods funnel through, so it's the right extension point
+    /// for new `user.*` endpoints.
+    pub async fn get<T: DeserializeOwned>(
         &self,
         method: &str,
         params: &QueryParams,
-    ) -> Result<T, Error> {
-        let mut final_params = self.base_options.clone();
-        final_params.insert("method".to_string(), method.to_string());
-        final_params.extend(params.clone());
+    ) -> Result<T, LastFmError> {
+        let mut attempt = 0;
+
+        loop {
+            // Hold the permit only for the request itself — a backing-off retry is
+            // idle time, not in-flight work, and shouldn't occupy a concurrency slot
+            // that a healthy request could otherwise use.
+            let result = {
+                let _permit = self
+                    .semaphore
+                    .acquire()
+                    .await
+                    .expect("semaphore is never closed");
+
+                self.fetch::<T>(method, params).await
+            };
+
+            let e = match result {
+                Ok(value) => return Ok(value),
+                Err(e) => e,
+            };
+
+            if attempt >= MAX_RETRIES || !Self::is_retryable(&e) {
+                return Err(e);
+            }
 
-        let base_url = self.url.clone().add_args(final_params).build();
+            let backoff = match &e {
+                LastFmError::RateLimited { retry_after } => *retry_after,
+                _ => Self::backoff_delay(attempt),
+            };
+            warn!(
+                method,
+                error = %e,
+                attempt = attempt + 1,
+                max_retries = MAX_RETRIES,
+                backoff = ?backoff,
+                "fetch failed, retrying"
+            );
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
 
-        println!("Fetching: {}", base_url);
+    /// Only transient failures are worth retrying: a dropped connection, a 429, or
+    /// Last.fm's own rate-limit signal embedded in a 200 response (error code 29). A
+    /// bad API key, unknown user, or malformed response is deterministic — retrying it
+    /// just burns `MAX_RETRIES` attempts and inflates failure metrics before surfacing
+    /// the same error anyway.
+    fn is_retryable(error: &LastFmError) -> bool {
+        matches!(
+            error,
+            LastFmError::Transport(_)
+                | LastFmError::RateLimited { .. }
+                | LastFmError::Api { code: 29, .. }
+        )
+    }
 
-        let response = reqwest::get(&base_url).await?;
+    fn backoff_delay(attempt: u32) -> Duration {
+        let exp = BASE_BACKOFF.saturating_mul(2u32.saturating_pow(attempt));
+        let capped = exp.min(MAX_BACKOFF);
+        let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..=100));
 
-        let parsed_response = response.json::<T>().await?;
+        capped + jitter
+    }
 
-        Ok(parsed_response)
+    /// Issues a single request and records its outcome: a `lastfm_requests_total`
+    /// counter labeled by `method`/outcome, and a `lastfm_request_duration_seconds`
+    /// histogram, both exposed through the `metrics` crate facade so a host
+    /// application can scrape them via Prometheus/OpenTelemetry by installing a
+    /// recorder. Does not itself retry; see [`LastFMHandler::get`].
+    #[instrument(skip(self, params))]
+    async fn fetch<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: &QueryParams,
+    ) -> Result<T, LastFmError> {
+        let started_at = SystemTime::now();
+        let result = self.fetch_request::<T>(method, params).await;
+        let elapsed = started_at.elapsed().unwrap_or_default();
+
+        let outcome = if result.is_ok() { "success" } else { "failure" };
+        counter!(
+            "lastfm_requests_total",
+            "method" => method.to_string(),
+            "outcome" => outcome
+        )
+        .increment(1);
+        histogram!("lastfm_request_duration_seconds", "method" => method.to_string())
+            .record(elapsed.as_secs_f64());
+
+        debug!(
+            method,
+            outcome,
+            elapsed_ms = elapsed.as_millis() as u64,
+            "fetch complete"
+        );
+
+        result
     }
 
-    async fn test_fetch(
+    async fn fetch_request<T: DeserializeOwned>(
         &self,
         method: &str,
         params: &QueryParams,
-    ) -> Result<UserRecentTracks, Error> {
+    ) -> Result<T, LastFmError> {
         let mut final_params = self.base_options.clone();
         final_params.insert("method".to_string(), method.to_string());
         final_params.extend(params.clone());
 
         let base_url = self.url.clone().add_args(final_params).build();
 
-        println!("[TEST] Fetching: {}", base_url);
+        trace!(url = %base_url, "fetching");
+
+        let response = reqwest::get(&base_url).await?;
 
-        let a: UserRecentTracks = UserRecentTracks {
-            recenttracks: RecentTracks {
-                track: vec![],
-                attr: BaseResponse {
-                    user: "tom".to_string(),
-                    total: 0,
-                    total_pages: 0,
-                    page: 0,
-                    per_page: 0,
-                },
-            },
-        };
+        if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(BASE_BACKOFF);
+
+            // Surface this to the caller instead of sleeping here: `get`'s retry loop
+            // honors `retry_after` after releasing the concurrency permit, rather than
+            // holding it idle for the duration.
+            return Err(LastFmError::RateLimited { retry_after });
+        }
+
+        // Last.fm reports application-level errors (bad key, unknown user, rate limit,
+        // ...) as a `{"error": ..., "message": ...}` body under HTTP 200, so the body is
+        // read once and checked for that envelope before we attempt to deserialize it
+        // into the caller's expected type.
+        let body = response.text().await?;
+
+        if let Ok(envelope) = serde_json::from_str::<ApiErrorEnvelope>(&body) {
+            return Err(LastFmError::Api {
+                code: envelope.error,
+                message: envelope.message,
+            });
+        }
 
-        Ok(a)
+        let parsed_response = serde_json::from_str::<T>(&body).map_err(LastFmError::Decode)?;
+
+        Ok(parsed_response)
     }
 }
 
@@ -201,6 +730,9 @@ trait TrackContainer {
     type TrackType;
 
     fn total_tracks(&self) -> u32;
+    fn total_pages(&self) -> u32;
+    /// Number of tracks in *this* page's response, prior to consuming it with `tracks`.
+    fn page_track_count(&self) -> u32;
     fn tracks(self) -> Vec<Self::TrackType>;
 }
 
@@ -211,6 +743,14 @@ impl TrackContainer for UserLovedTracks {
         self.lovedtracks.attr.total
     }
 
+    fn total_pages(&self) -> u32 {
+        self.lovedtracks.attr.total_pages
+    }
+
+    fn page_track_count(&self) -> u32 {
+        self.lovedtracks.track.len() as u32
+    }
+
     fn tracks(self) -> Vec<Self::TrackType> {
         self.lovedtracks.track
     }
@@ -223,7 +763,164 @@ impl TrackContainer for UserRecentTracks {
         self.recenttracks.attr.total
     }
 
+    fn total_pages(&self) -> u32 {
+        self.recenttracks.attr.total_pages
+    }
+
+    fn page_track_count(&self) -> u32 {
+        self.recenttracks.track.len() as u32
+    }
+
     fn tracks(self) -> Vec<Self::TrackType> {
         self.recenttracks.track
     }
 }
+
+/// Analogous to [`TrackContainer`], but for the `user.gettop*` family of endpoints,
+/// whose responses wrap a list of artists/albums/tracks rather than scrobbles.
+trait ItemContainer {
+    type Item;
+
+    fn total_items(&self) -> u32;
+    fn total_pages(&self) -> u32;
+    /// Number of items in *this* page's response, prior to consuming it with `items`.
+    fn page_item_count(&self) -> u32;
+    fn items(self) -> Vec<Self::Item>;
+}
+
+impl ItemContainer for UserTopArtists {
+    type Item = TopArtist;
+
+    fn total_items(&self) -> u32 {
+        self.topartists.attr.total
+    }
+
+    fn total_pages(&self) -> u32 {
+        self.topartists.attr.total_pages
+    }
+
+    fn page_item_count(&self) -> u32 {
+        self.topartists.artist.len() as u32
+    }
+
+    fn items(self) -> Vec<Self::Item> {
+        self.topartists.artist
+    }
+}
+
+impl ItemContainer for UserTopAlbums {
+    type Item = TopAlbum;
+
+    fn total_items(&self) -> u32 {
+        self.topalbums.attr.total
+    }
+
+    fn total_pages(&self) -> u32 {
+        self.topalbums.attr.total_pages
+    }
+
+    fn page_item_count(&self) -> u32 {
+        self.topalbums.album.len() as u32
+    }
+
+    fn items(self) -> Vec<Self::Item> {
+        self.topalbums.album
+    }
+}
+
+impl ItemContainer for UserTopTracks {
+    type Item = TopTrack;
+
+    fn total_items(&self) -> u32 {
+        self.toptracks.attr.total
+    }
+
+    fn total_pages(&self) -> u32 {
+        self.toptracks.attr.total_pages
+    }
+
+    fn page_item_count(&self) -> u32 {
+        self.toptracks.track.len() as u32
+    }
+
+    fn items(self) -> Vec<Self::Item> {
+        self.toptracks.track
+    }
+}
+
+/// Per-page cursor state for [`paginate`]'s lazy stream: the page to fetch next, the
+/// total page count (learned from the first response and refreshed from each
+/// subsequent one), and whatever tracks from the current page haven't been yielded yet.
+struct PaginationState<'a, T: TrackContainer> {
+    handler: &'a LastFMHandler,
+    method: &'static str,
+    next_page: u32,
+    total_pages: Option<u32>,
+    buffered: std::collections::VecDeque<T::TrackType>,
+    /// Set once a fetch has failed and its `Err` has been yielded. `get` already retries
+    /// transient failures to exhaustion, so a page that still errors out is permanent for
+    /// this walk — without this, a consumer that logs-and-continues past the error would
+    /// see the stream re-fetch and re-fail the same page forever.
+    done: bool,
+}
+
+/// Builds a lazy, page-at-a-time stream over a Last.fm `user.*` listing. Only one page
+/// is ever held in memory: the buffer is drained track-by-track, and the next page is
+/// fetched only once the buffer runs dry, so consumers can walk arbitrarily large
+/// histories with `while let Some(track) = stream.next().await` instead of collecting
+/// everything into a `Vec` up front.
+fn paginate<T>(
+    handler: &LastFMHandler,
+    method: &'static str,
+) -> impl futures::Stream<Item = Result<T::TrackType, LastFmError>> + '_
+where
+    T: DeserializeOwned + TrackContainer,
+{
+    let state = PaginationState::<T> {
+        handler,
+        method,
+        next_page: 1,
+        total_pages: None,
+        buffered: std::collections::VecDeque::new(),
+        done: false,
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if state.done {
+                return None;
+            }
+
+            if let Some(track) = state.buffered.pop_front() {
+                return Some((Ok(track), state));
+            }
+
+            if let Some(total_pages) = state.total_pages {
+                if state.next_page > total_pages {
+                    return None;
+                }
+            }
+
+            let mut params: QueryParams = HashMap::new();
+            params.insert("limit".to_string(), API_MAX_LIMIT.to_string());
+            params.insert("page".to_string(), state.next_page.to_string());
+
+            let response: T = match state.handler.get(state.method, &params).await {
+                Ok(response) => response,
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+            };
+
+            state.total_pages = Some(response.total_pages());
+            state.next_page += 1;
+            state.buffered.extend(response.tracks());
+
+            // Don't treat an empty page as end-of-stream: a page can legitimately come
+            // back empty mid-walk (e.g. its tracks were deleted between counts) while
+            // later pages still exist. Apart from a failed fetch (`done`), the
+            // `next_page > total_pages` check above is the only thing that ends the stream.
+        }
+    })
+}